@@ -26,11 +26,15 @@ use config::{
     PARQUET_BATCH_SIZE,
 };
 use datafusion::{
+    arrow,
     arrow::datatypes::{DataType, Schema},
     catalog::TableProvider,
     common::Column,
     datasource::{
-        file_format::parquet::ParquetFormat,
+        file_format::{
+            avro::AvroFormat, csv::CsvFormat, json::JsonFormat, parquet::ParquetFormat,
+            FileFormat as DFFileFormat,
+        },
         listing::{ListingOptions, ListingTableConfig, ListingTableUrl},
         object_store::{DefaultObjectStoreRegistry, ObjectStoreRegistry},
     },
@@ -46,6 +50,7 @@ use datafusion::{
     optimizer::OptimizerRule,
     physical_plan::execute_stream,
     prelude::{Expr, SessionContext},
+    scalar::ScalarValue,
 };
 use futures::TryStreamExt;
 use hashbrown::HashMap;
@@ -53,6 +58,7 @@ use hashbrown::HashMap;
 use o2_enterprise::enterprise::{
     common::infra::config::get_config as get_o2_config, search::WorkGroup,
 };
+use sysinfo::System;
 
 use super::{
     file_type::{FileType, GetExt},
@@ -60,15 +66,49 @@ use super::{
     planner::extension_planner::OpenobserveQueryPlanner,
     storage::file_list,
     table_provider::{uniontable::NewUnionTable, NewListingTable},
-    udf::transform_udf::get_all_transform,
+    udf::{scripting_udf::get_all_scripting_udf, transform_udf::get_all_transform},
 };
 use crate::service::{
     metadata::distinct_values::DISTINCT_STREAM_PREFIX, search::index::IndexCondition,
 };
 
-const DATAFUSION_MIN_MEM: usize = 1024 * 1024 * 256; // 256MB
 const DATAFUSION_MIN_PARTITION: usize = 2; // CPU cores
 
+/// Container format for a stream's files, alongside `FileType`/`GetExt`. `Avro`
+/// is input-only: DataFusion has no Avro writer, so `merge_parquet_files`
+/// rejects it as an `output_format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileFormat {
+    Parquet,
+    Csv,
+    Json,
+    Avro,
+}
+
+impl FileFormat {
+    fn file_extension(&self) -> &'static str {
+        match self {
+            FileFormat::Parquet => FileType::PARQUET.get_ext(),
+            FileFormat::Csv => ".csv",
+            FileFormat::Json => ".json",
+            FileFormat::Avro => ".avro",
+        }
+    }
+
+    // `parquet_options` is ignored for non-parquet formats
+    fn datafusion_format(
+        &self,
+        parquet_options: datafusion::config::TableParquetOptions,
+    ) -> Arc<dyn DFFileFormat> {
+        match self {
+            FileFormat::Parquet => Arc::new(ParquetFormat::default().with_options(parquet_options)),
+            FileFormat::Csv => Arc::new(CsvFormat::default().with_has_header(true)),
+            FileFormat::Json => Arc::new(JsonFormat::default()),
+            FileFormat::Avro => Arc::new(AvroFormat::default()),
+        }
+    }
+}
+
 pub async fn merge_parquet_files(
     stream_type: StreamType,
     stream_name: &str,
@@ -76,6 +116,7 @@ pub async fn merge_parquet_files(
     tables: Vec<Arc<dyn TableProvider>>,
     bloom_filter_fields: &[String],
     metadata: &FileMeta,
+    output_format: FileFormat,
 ) -> Result<(Arc<Schema>, Vec<u8>)> {
     let start = std::time::Instant::now();
     let cfg = get_config();
@@ -116,8 +157,15 @@ pub async fn merge_parquet_files(
     // create datafusion context
     let sort_by_timestamp_desc = true;
     let target_partitions = cfg.limit.cpu_num;
-    let ctx =
-        prepare_datafusion_context(None, vec![], sort_by_timestamp_desc, target_partitions).await?;
+    // compaction always reads full files, so predicate pushdown never pays off here
+    let ctx = prepare_datafusion_context(
+        None,
+        vec![],
+        sort_by_timestamp_desc,
+        target_partitions,
+        false,
+    )
+    .await?;
     // register union table
     let union_table = Arc::new(NewUnionTable::try_new(schema.clone(), tables)?);
     ctx.register_table("tbl", union_table)?;
@@ -126,28 +174,67 @@ pub async fn merge_parquet_files(
     let physical_plan = ctx.state().create_physical_plan(&plan).await?;
     let schema = physical_plan.schema();
 
-    // write result to parquet file
+    // write result to the requested output format
     let mut buf = Vec::new();
-    let mut writer = new_parquet_writer(&mut buf, &schema, bloom_filter_fields, metadata);
     let mut batch_stream = execute_stream(physical_plan, ctx.task_ctx())?;
-    loop {
-        match batch_stream.try_next().await {
-            Ok(Some(batch)) => {
-                if let Err(e) = writer.write(&batch).await {
-                    log::error!("merge_parquet_files write Error: {}", e);
-                    return Err(e.into());
+    match output_format {
+        FileFormat::Parquet => {
+            let mut writer = new_parquet_writer(&mut buf, &schema, bloom_filter_fields, metadata);
+            loop {
+                match batch_stream.try_next().await {
+                    Ok(Some(batch)) => {
+                        if let Err(e) = writer.write(&batch).await {
+                            log::error!("merge_parquet_files write Error: {}", e);
+                            return Err(e.into());
+                        }
+                    }
+                    Ok(None) => break,
+                    Err(e) => {
+                        log::error!("merge_parquet_files execute stream Error: {}", e);
+                        return Err(e);
+                    }
                 }
             }
-            Ok(None) => {
-                break;
+            writer.close().await?;
+        }
+        FileFormat::Csv => {
+            let mut writer = arrow::csv::WriterBuilder::new()
+                .with_header(true)
+                .build(&mut buf);
+            loop {
+                match batch_stream.try_next().await {
+                    Ok(Some(batch)) => writer.write(&batch)?,
+                    Ok(None) => break,
+                    Err(e) => {
+                        log::error!("merge_parquet_files execute stream Error: {}", e);
+                        return Err(e);
+                    }
+                }
             }
-            Err(e) => {
-                log::error!("merge_parquet_files execute stream Error: {}", e);
-                return Err(e);
+        }
+        FileFormat::Json => {
+            // newline-delimited, the same layout `JsonFormat` reads back
+            let mut writer = arrow::json::LineDelimitedWriter::new(&mut buf);
+            loop {
+                match batch_stream.try_next().await {
+                    Ok(Some(batch)) => writer.write(&batch)?,
+                    Ok(None) => break,
+                    Err(e) => {
+                        log::error!("merge_parquet_files execute stream Error: {}", e);
+                        return Err(e);
+                    }
+                }
             }
+            writer.finish()?;
+        }
+        // not a gap to fill in later: DataFusion does not ship an Avro writer,
+        // so Avro is input-only (see the `FileFormat` doc comment)
+        FileFormat::Avro => {
+            return Err(DataFusionError::NotImplemented(
+                "merge_parquet_files does not support writing Avro output".to_string(),
+            ));
         }
     }
-    writer.close().await?;
 
     ctx.deregister_table("tbl")?;
     drop(ctx);
@@ -163,6 +250,7 @@ pub async fn merge_parquet_files(
 pub fn create_session_config(
     sorted_by_time: bool,
     target_partitions: usize,
+    enable_pushdown: bool,
 ) -> Result<SessionConfig> {
     let cfg = get_config();
     let mut target_partitions = if target_partitions == 0 {
@@ -183,9 +271,14 @@ pub fn create_session_config(
         .listing_table_ignore_subdirectory = false;
     config.options_mut().sql_parser.dialect = "PostgreSQL".to_string();
 
-    // based on data distributing, it only works for the data on a few records
-    // config = config.set_bool("datafusion.execution.parquet.pushdown_filters", true);
-    // config = config.set_bool("datafusion.execution.parquet.reorder_filters", true);
+    // only worth it on selective searches (e.g. an index condition backed by
+    // bloom filters): large scan-heavy merges keep these off since pushdown
+    // and reorder add per-row-group overhead that isn't repaid when most of
+    // the data is going to be read anyway
+    if enable_pushdown {
+        config = config.set_bool("datafusion.execution.parquet.pushdown_filters", true);
+        config = config.set_bool("datafusion.execution.parquet.reorder_filters", true);
+    }
 
     if cfg.common.bloom_filter_enabled {
         config = config.set_bool("datafusion.execution.parquet.bloom_filter_on_read", true);
@@ -207,6 +300,120 @@ pub fn create_session_config(
     Ok(config)
 }
 
+// parses e.g. "256MB", "2GB", "1.5G" or "60%" of total system RAM into a byte
+// count
+pub fn parse_memory_size(input: &str) -> Result<usize> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(DataFusionError::Configuration(
+            "memory size string is empty".to_string(),
+        ));
+    }
+
+    if let Some(pct) = input.strip_suffix('%') {
+        let pct: f64 = pct.trim().parse().map_err(|_| {
+            DataFusionError::Configuration(format!("invalid memory size percentage: {input}"))
+        })?;
+        if !(0.0..=100.0).contains(&pct) {
+            return Err(DataFusionError::Configuration(format!(
+                "memory size percentage must be between 0 and 100, got: {input}"
+            )));
+        }
+        let total_mem = total_system_memory()?;
+        return Ok(((total_mem as f64) * pct / 100.0).floor() as usize);
+    }
+
+    const SUFFIXES: &[(&str, f64)] = &[
+        ("TB", 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("T", 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("GB", 1024.0 * 1024.0 * 1024.0),
+        ("G", 1024.0 * 1024.0 * 1024.0),
+        ("MB", 1024.0 * 1024.0),
+        ("M", 1024.0 * 1024.0),
+        ("KB", 1024.0),
+        ("K", 1024.0),
+    ];
+    let upper = input.to_uppercase();
+    let (num_part, multiplier) = SUFFIXES
+        .iter()
+        .find(|(suffix, _)| upper.ends_with(suffix))
+        .map(|(suffix, mult)| (&input[..input.len() - suffix.len()], *mult))
+        .unwrap_or((input, 1.0));
+
+    let num: f64 = num_part.trim().parse().map_err(|_| {
+        DataFusionError::Configuration(format!("invalid memory size string: {input}"))
+    })?;
+    if num < 0.0 {
+        return Err(DataFusionError::Configuration(format!(
+            "memory size must not be negative, got: {input}"
+        )));
+    }
+    Ok((num * multiplier).floor() as usize)
+}
+
+fn total_system_memory() -> Result<u64> {
+    let mut sys = System::new();
+    sys.refresh_memory();
+    Ok(sys.total_memory())
+}
+
+#[cfg(test)]
+mod parse_memory_size_tests {
+    use super::parse_memory_size;
+
+    #[test]
+    fn parses_bytes_with_no_suffix() {
+        assert_eq!(parse_memory_size("1024").unwrap(), 1024);
+    }
+
+    #[test]
+    fn parses_binary_suffixes_case_insensitively() {
+        assert_eq!(parse_memory_size("256MB").unwrap(), 256 * 1024 * 1024);
+        assert_eq!(parse_memory_size("256mb").unwrap(), 256 * 1024 * 1024);
+        assert_eq!(parse_memory_size("2GB").unwrap(), 2 * 1024 * 1024 * 1024);
+        assert_eq!(parse_memory_size("2g").unwrap(), 2 * 1024 * 1024 * 1024);
+    }
+
+    #[test]
+    fn parses_fractional_suffixes() {
+        assert_eq!(
+            parse_memory_size("1.5G").unwrap(),
+            (1.5 * 1024.0 * 1024.0 * 1024.0) as usize
+        );
+    }
+
+    #[test]
+    fn parses_percentage_of_total_memory() {
+        let total = super::total_system_memory().unwrap();
+        let expected = ((total as f64) * 0.6).floor() as usize;
+        assert_eq!(parse_memory_size("60%").unwrap(), expected);
+    }
+
+    #[test]
+    fn rejects_empty_input() {
+        assert!(parse_memory_size("").is_err());
+        assert!(parse_memory_size("   ").is_err());
+    }
+
+    #[test]
+    fn rejects_garbage_input() {
+        assert!(parse_memory_size("not-a-size").is_err());
+        assert!(parse_memory_size("MB").is_err());
+    }
+
+    #[test]
+    fn rejects_negative_input() {
+        assert!(parse_memory_size("-1").is_err());
+        assert!(parse_memory_size("-5GB").is_err());
+    }
+
+    #[test]
+    fn rejects_percentage_out_of_range() {
+        assert!(parse_memory_size("150%").is_err());
+        assert!(parse_memory_size("-10%").is_err());
+    }
+}
+
 pub async fn create_runtime_env(memory_limit: usize) -> Result<RuntimeEnv> {
     let object_store_registry = DefaultObjectStoreRegistry::new();
 
@@ -233,7 +440,10 @@ pub async fn create_runtime_env(memory_limit: usize) -> Result<RuntimeEnv> {
         rn_config = rn_config.with_cache_manager(cache_config);
     }
 
-    let memory_size = std::cmp::max(DATAFUSION_MIN_MEM, memory_limit);
+    // the pool never gets less than 256MB; `memory_limit` is already a byte
+    // count and is used as is when it is larger
+    let min_mem = parse_memory_size("256MB")?;
+    let memory_size = std::cmp::max(min_mem, memory_limit);
     let mem_pool = super::MemoryPoolType::from_str(&cfg.memory_cache.datafusion_memory_pool)
         .map_err(|e| {
             DataFusionError::Execution(format!("Invalid datafusion memory pool type: {}", e))
@@ -255,6 +465,7 @@ pub async fn prepare_datafusion_context(
     optimizer_rules: Vec<Arc<dyn OptimizerRule + Send + Sync>>,
     sorted_by_time: bool,
     target_partitions: usize,
+    enable_pushdown: bool,
 ) -> Result<SessionContext, DataFusionError> {
     let cfg = get_config();
     #[cfg(not(feature = "enterprise"))]
@@ -265,7 +476,7 @@ pub async fn prepare_datafusion_context(
     let (target_partition, memory_size) =
         get_cpu_and_mem_limit(_work_group.clone(), target_partition, memory_size).await?;
 
-    let session_config = create_session_config(sorted_by_time, target_partition)?;
+    let session_config = create_session_config(sorted_by_time, target_partition, enable_pushdown)?;
     let runtime_env = Arc::new(create_runtime_env(memory_size).await?);
     let mut builder = SessionStateBuilder::new()
         .with_config(session_config)
@@ -316,6 +527,15 @@ pub fn register_udf(ctx: &SessionContext, org_id: &str) -> Result<()> {
         ctx.register_udf(udf.clone());
     }
 
+    // per-org scripting UDFs (WASM/Python), registered last so they can
+    // override a transform UDF of the same name for that org; currently a
+    // no-op for every org since get_all_scripting_udf has no backing store
+    // or runtime yet (see udf::scripting_udf)
+    let scripting_udf_list = get_all_scripting_udf(org_id)?;
+    for udf in scripting_udf_list {
+        ctx.register_udf(udf.clone());
+    }
+
     Ok(())
 }
 
@@ -327,17 +547,25 @@ pub async fn register_table(
     files: &[FileKey],
     rules: HashMap<String, DataType>,
     sort_key: &[(String, bool)],
+    index_condition: Option<IndexCondition>,
+    input_format: FileFormat,
+    partition_cols: PartitionCols,
 ) -> Result<SessionContext> {
     let cfg = get_config();
     // only sort by timestamp desc
     let sorted_by_time =
         sort_key.len() == 1 && sort_key[0].0 == cfg.common.column_timestamp && sort_key[0].1;
+    // a query with an index condition is selective enough (equality / match_all
+    // backed by bloom filters) that row-group and page pruning pays for itself;
+    // a plain scan over the whole stream is not
+    let enable_pushdown = index_condition.is_some();
 
     let ctx = prepare_datafusion_context(
         session.work_group.clone(),
         vec![],
         sorted_by_time,
         session.target_partitions,
+        enable_pushdown,
     )
     .await?;
 
@@ -348,8 +576,10 @@ pub async fn register_table(
         rules.clone(),
         sorted_by_time,
         ctx.runtime_env().cache_manager.get_file_statistic_cache(),
-        None,
+        index_condition,
         vec![],
+        partition_cols,
+        input_format,
     )
     .await?;
     ctx.register_table(table_name, table)?;
@@ -357,6 +587,127 @@ pub async fn register_table(
     Ok(ctx)
 }
 
+// Hive-style `key=value` path segments registered as virtual partition
+// columns, e.g. `[("year", DataType::Int32)]` for `.../year=2024/...`
+pub type PartitionCols = Vec<(String, DataType)>;
+
+// falls back to Utf8 for a declared type we don't coerce; errors when the
+// declared type is known but the value doesn't parse as that type
+fn parse_partition_value(raw: &str, data_type: &DataType) -> Result<ScalarValue> {
+    let value = match data_type {
+        DataType::Int8 => ScalarValue::Int8(Some(raw.parse().map_err(|e| {
+            DataFusionError::Execution(format!("invalid Int8 partition value {raw:?}: {e}"))
+        })?)),
+        DataType::Int16 => ScalarValue::Int16(Some(raw.parse().map_err(|e| {
+            DataFusionError::Execution(format!("invalid Int16 partition value {raw:?}: {e}"))
+        })?)),
+        DataType::Int32 => ScalarValue::Int32(Some(raw.parse().map_err(|e| {
+            DataFusionError::Execution(format!("invalid Int32 partition value {raw:?}: {e}"))
+        })?)),
+        DataType::Int64 => ScalarValue::Int64(Some(raw.parse().map_err(|e| {
+            DataFusionError::Execution(format!("invalid Int64 partition value {raw:?}: {e}"))
+        })?)),
+        DataType::UInt32 => ScalarValue::UInt32(Some(raw.parse().map_err(|e| {
+            DataFusionError::Execution(format!("invalid UInt32 partition value {raw:?}: {e}"))
+        })?)),
+        DataType::UInt64 => ScalarValue::UInt64(Some(raw.parse().map_err(|e| {
+            DataFusionError::Execution(format!("invalid UInt64 partition value {raw:?}: {e}"))
+        })?)),
+        DataType::Boolean => ScalarValue::Boolean(Some(raw.parse().map_err(|e| {
+            DataFusionError::Execution(format!("invalid Boolean partition value {raw:?}: {e}"))
+        })?)),
+        DataType::Utf8 => ScalarValue::Utf8(Some(raw.to_string())),
+        // unknown declared type: keep the raw string rather than failing the query
+        _ => ScalarValue::Utf8(Some(raw.to_string())),
+    };
+    Ok(value)
+}
+
+// DataFusion's own partition pruning (`with_table_partition_cols` /
+// `pruned_partition_list`) re-parses these same segments itself; this pass
+// exists only so a file with an unparseable partition value errors out here
+// with a readable message instead of being silently dropped/ignored deeper
+// in DataFusion. It validates rather than returning `Vec<ScalarValue>` so it
+// doesn't allocate values nothing downstream reuses.
+fn validate_partition_values_for_file(
+    file: &FileKey,
+    partition_cols: &[(String, DataType)],
+) -> Result<()> {
+    validate_partition_segments(&file.key, partition_cols)
+}
+
+fn validate_partition_segments(key: &str, partition_cols: &[(String, DataType)]) -> Result<()> {
+    let segments: HashMap<&str, &str> = key
+        .split('/')
+        .filter_map(|seg| seg.split_once('='))
+        .collect();
+    for (name, data_type) in partition_cols {
+        let raw = segments.get(name.as_str()).copied().unwrap_or("");
+        parse_partition_value(raw, data_type)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod partition_value_tests {
+    use super::{parse_partition_value, validate_partition_segments};
+    use datafusion::{arrow::datatypes::DataType, scalar::ScalarValue};
+
+    #[test]
+    fn parses_known_types() {
+        assert_eq!(
+            parse_partition_value("2024", &DataType::Int32).unwrap(),
+            ScalarValue::Int32(Some(2024))
+        );
+        assert_eq!(
+            parse_partition_value("true", &DataType::Boolean).unwrap(),
+            ScalarValue::Boolean(Some(true))
+        );
+        assert_eq!(
+            parse_partition_value("acme", &DataType::Utf8).unwrap(),
+            ScalarValue::Utf8(Some("acme".to_string()))
+        );
+    }
+
+    #[test]
+    fn falls_back_to_utf8_for_undeclared_type() {
+        assert_eq!(
+            parse_partition_value("acme", &DataType::Float64).unwrap(),
+            ScalarValue::Utf8(Some("acme".to_string()))
+        );
+    }
+
+    #[test]
+    fn rejects_unparseable_value_for_declared_type() {
+        assert!(parse_partition_value("not-a-number", &DataType::Int32).is_err());
+        assert!(parse_partition_value("maybe", &DataType::Boolean).is_err());
+    }
+
+    #[test]
+    fn validates_all_partition_columns_in_key() {
+        let partition_cols = vec![
+            ("year".to_string(), DataType::Int32),
+            ("org".to_string(), DataType::Utf8),
+        ];
+        assert!(
+            validate_partition_segments("year=2024/org=acme/file.parquet", &partition_cols).is_ok()
+        );
+        assert!(validate_partition_segments(
+            "year=not-a-year/org=acme/file.parquet",
+            &partition_cols
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn missing_segment_falls_back_to_empty_raw_value() {
+        // a missing "year" segment parses as "" against Int32 and errors,
+        // same as an unparseable value would
+        let partition_cols = vec![("year".to_string(), DataType::Int32)];
+        assert!(validate_partition_segments("org=acme/file.parquet", &partition_cols).is_err());
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn create_parquet_table(
     session: &SearchSession,
@@ -367,8 +718,17 @@ pub async fn create_parquet_table(
     file_stat_cache: Option<FileStatisticsCache>,
     index_condition: Option<IndexCondition>,
     fst_fields: Vec<String>,
+    partition_cols: PartitionCols,
+    input_format: FileFormat,
 ) -> Result<Arc<dyn TableProvider>> {
     let cfg = get_config();
+    for (name, _) in partition_cols.iter() {
+        if schema.field_with_name(name).is_ok() {
+            return Err(DataFusionError::Plan(format!(
+                "partition column {name:?} collides with an existing schema field"
+            )));
+        }
+    }
     let target_partitions = if session.target_partitions == 0 {
         cfg.limit.cpu_num
     } else {
@@ -388,13 +748,29 @@ pub async fn create_parquet_table(
         target_partitions
     };
 
-    // Configure listing options
-    let file_format = ParquetFormat::default();
-    let mut listing_options = ListingOptions::new(Arc::new(file_format))
-        .with_file_extension(FileType::PARQUET.get_ext())
+    // Configure listing options. An index condition means the search is
+    // selective enough that row-group/page pruning against parquet statistics
+    // and bloom filters pays for itself; a plain scan leaves pruning off.
+    // Only parquet actually honors these options; they're no-ops for the
+    // other input formats.
+    let enable_pruning = index_condition.is_some();
+    let mut parquet_options = datafusion::config::TableParquetOptions::new();
+    parquet_options.global.pruning = enable_pruning;
+    parquet_options.global.pushdown_filters = enable_pruning;
+    parquet_options.global.reorder_filters = enable_pruning;
+    let file_format = input_format.datafusion_format(parquet_options);
+    let mut listing_options = ListingOptions::new(file_format)
+        .with_file_extension(input_format.file_extension())
         .with_target_partitions(target_partitions)
         .with_collect_stat(true);
 
+    if !partition_cols.is_empty() {
+        // registering these lets `pruned_partition_list` eliminate whole
+        // directories against predicates on the partition columns before any
+        // parquet file is opened, instead of pruning only at the row-group level
+        listing_options = listing_options.with_table_partition_cols(partition_cols.clone());
+    }
+
     if sorted_by_time {
         // specify sort columns for parquet file
         listing_options =
@@ -405,6 +781,12 @@ pub async fn create_parquet_table(
             }]]);
     }
 
+    if !partition_cols.is_empty() {
+        for file in files {
+            validate_partition_values_for_file(file, &partition_cols)?;
+        }
+    }
+
     let schema_key = schema.hash_key();
     let prefix = if session.storage_type == StorageType::Memory {
         file_list::set(&session.id, &schema_key, files).await;
@@ -452,7 +834,8 @@ pub async fn create_parquet_table(
         schema
     };
     config = config.with_schema(schema);
-    let mut table = NewListingTable::try_new(config, rules, index_condition, fst_fields)?;
+    let mut table =
+        NewListingTable::try_new(config, rules, index_condition, fst_fields, partition_cols)?;
     if session.storage_type != StorageType::Tmpfs && file_stat_cache.is_some() {
         table = table.with_cache(file_stat_cache);
     }
@@ -0,0 +1,126 @@
+// Copyright 2024 OpenObserve Inc.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+//
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Scripting UDF subsystem: registers scalar UDFs backed by a WASM/Python
+//! script instead of Rust code.
+//!
+//! NOT YET IMPLEMENTED. This is registration scaffolding only — there is no
+//! per-org UDF store lookup and no WASM/Python runtime. `load_scripting_udf_defs`
+//! always returns an empty list, so `get_all_scripting_udf` never registers
+//! anything, and `invoke_wasm`/`invoke_python` would error on every call even
+//! if it did. `max_execution`/`max_memory_bytes` are unread. Embedding a real
+//! runtime (e.g. wasmtime or an embedded Python) is follow-up work.
+
+use std::{any::Any, time::Duration};
+
+use datafusion::{
+    arrow::datatypes::DataType,
+    error::{DataFusionError, Result},
+    logical_expr::{
+        ColumnarValue, ScalarFunctionArgs, ScalarUDF, ScalarUDFImpl, Signature, Volatility,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptRuntime {
+    Wasm,
+    Python,
+}
+
+#[derive(Debug, Clone)]
+pub struct ScriptingUdfDef {
+    pub name: String,
+    pub runtime: ScriptRuntime,
+    pub arg_types: Vec<DataType>,
+    pub return_type: DataType,
+    pub script: Vec<u8>,
+    pub max_execution: Duration,
+    pub max_memory_bytes: usize,
+}
+
+// Not implemented: there is no per-org UDF definition store to read from
+// yet, so this always returns an empty list regardless of `org_id`.
+fn load_scripting_udf_defs(org_id: &str) -> Result<Vec<ScriptingUdfDef>> {
+    let _ = org_id;
+    Ok(vec![])
+}
+
+#[derive(Debug)]
+struct ScriptingScalarUdf {
+    def: ScriptingUdfDef,
+    signature: Signature,
+}
+
+impl ScriptingScalarUdf {
+    fn new(def: ScriptingUdfDef) -> Self {
+        let signature = Signature::exact(def.arg_types.clone(), Volatility::Volatile);
+        Self { def, signature }
+    }
+
+    fn invoke_script(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        match self.def.runtime {
+            ScriptRuntime::Wasm => self.invoke_wasm(args),
+            ScriptRuntime::Python => self.invoke_python(args),
+        }
+    }
+
+    // The actual WASM/Python execution (module instantiation, the
+    // max_execution timeout, the max_memory_bytes limiter) is intentionally
+    // left as the integration seam: wiring in a real embedded runtime is a
+    // separate change. Until then a scripting UDF call fails loudly instead
+    // of silently returning nulls.
+    fn invoke_wasm(&self, _args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        Err(DataFusionError::Execution(format!(
+            "scripting UDF '{}': no WASM runtime is configured",
+            self.def.name
+        )))
+    }
+
+    fn invoke_python(&self, _args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        Err(DataFusionError::Execution(format!(
+            "scripting UDF '{}': no Python runtime is configured",
+            self.def.name
+        )))
+    }
+}
+
+impl ScalarUDFImpl for ScriptingScalarUdf {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.def.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(self.def.return_type.clone())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        self.invoke_script(&args.args)
+    }
+}
+
+pub fn get_all_scripting_udf(org_id: &str) -> Result<Vec<ScalarUDF>> {
+    load_scripting_udf_defs(org_id)?
+        .into_iter()
+        .map(|def| Ok(ScalarUDF::from(ScriptingScalarUdf::new(def))))
+        .collect()
+}